@@ -1,48 +1,285 @@
 #![no_std]
 
+// `lock_async`/`LockFuture` can't take any of the backend locks: their
+// success path runs inside `poll()`, and blocking there to acquire a
+// `lock`/`spin_lock`/`mcs_lock` can deadlock against a `wait`/`lock` caller
+// that's already holding that same backend lock while spinning on the
+// atomic state for readers to drain (it can never win the state back from
+// under a task that took it first, yet the task now blocks on the backend
+// lock `wait` holds). So `async-lock` only works with no backend-lock
+// feature enabled at all; without this check that combination would
+// silently compile with `poll()` occasionally deadlocking instead of
+// failing loudly at build time.
+#[cfg(all(
+    feature = "async-lock",
+    any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks")
+))]
+compile_error!(
+    "the \"async-lock\" feature can't be combined with \"use-locks\", \"spin-locks\", or \
+     \"mcs-locks\" yet: lock_async's poll() would have to block on the backend lock to honor \
+     it, which can deadlock against a wait()/lock() caller holding it. Enable \"async-lock\" \
+     with no backend-lock feature instead."
+);
+
+// The three backend-lock features pick the `Lock` implementation used to
+// serialize writers, so at most one can be enabled at a time: enabling more
+// than one (plausible via Cargo feature unification across a dependency
+// graph) imports two different items as `Lock` below, which on its own only
+// fails with a duplicate-import error that doesn't name the conflicting
+// features. This check names them instead.
+#[cfg(any(
+    all(feature = "use-locks", feature = "spin-locks"),
+    all(feature = "use-locks", feature = "mcs-locks"),
+    all(feature = "spin-locks", feature = "mcs-locks"),
+))]
+compile_error!(
+    "only one of \"use-locks\", \"spin-locks\", or \"mcs-locks\" can be enabled at a time: they \
+     each provide a different backend `Lock` implementation, and only one of them can be used \
+     as `Lock`. Pick a single backend-lock feature."
+);
+
 use core::{
     cell::UnsafeCell,
     fmt::{self, Debug},
     ops::{Deref, DerefMut},
+    sync::atomic::{AtomicUsize, Ordering},
 };
 #[cfg(feature = "use-locks")]
 use lock::Lock;
+#[cfg(feature = "spin-locks")]
+use spin_lock::Lock;
+#[cfg(feature = "mcs-locks")]
+use mcs_lock::{Lock, Node};
+
+#[cfg(feature = "mcs-locks")]
+extern crate alloc;
+#[cfg(feature = "mcs-locks")]
+use alloc::boxed::Box;
 
 #[cfg(feature = "use-locks")]
 mod lock;
+#[cfg(feature = "spin-locks")]
+mod spin_lock;
+#[cfg(feature = "mcs-locks")]
+mod mcs_lock;
+#[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+mod waker_queue;
+
+#[cfg(feature = "spin-locks")]
+pub use spin_lock::{Relax, Spin};
+#[cfg(all(feature = "spin-locks", feature = "std"))]
+pub use spin_lock::Yield;
 
+#[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+use core::{future::Future, marker::PhantomPinned, pin::Pin, task::Context, task::Poll};
+#[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+use waker_queue::{WakerNode, WakerQueue};
+
+/// A coarse view of a [`LazyExclusive`]'s state. [`StateCell`] actually
+/// tracks a reader count alongside the writer/poison bits summarized here;
+/// see [`LazyExclusive::get_state`].
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[non_exhaustive]
+#[repr(u8)]
 pub enum State {
-    Unlocked,
-    Locked,
-    Poisoned,
+    Unlocked = 0,
+    Locked = 1,
+    Poisoned = 2,
+    ReadLocked = 3,
 }
 
-/// A Cell for [`State`]. Used for const access to its data
+/// A writer currently holds exclusive access.
+const WRITER_BIT: usize = 1 << (usize::BITS - 1);
+/// A writer panicked while holding the data, and it hasn't been recovered
+/// via [`LazyExclusive::clear_poison`] since.
+const POISONED_BIT: usize = 1 << (usize::BITS - 2);
+/// A spinlock bit guarding the waiter list used by `lock_async`, packed into
+/// the same word as the reader count so enqueueing/dequeuing a waiter never
+/// needs a second atomic.
+const QUEUE_LOCK_BIT: usize = 1 << (usize::BITS - 3);
+/// The data has been forced past [`Data::Uninit`] at least once. Checked so
+/// that a reader racing another reader on first access doesn't try to force
+/// it twice; set permanently once a writer or reader has forced it.
+const INIT_BIT: usize = 1 << (usize::BITS - 4);
+/// The remaining low bits: how many readers currently hold a [`Ref`].
+const READER_MASK: usize = !(WRITER_BIT | POISONED_BIT | QUEUE_LOCK_BIT | INIT_BIT);
+/// The bits that describe locking state proper: whether `LazyExclusive` is
+/// idle, read-locked, write-locked, or poisoned. Excludes [`INIT_BIT`] (which
+/// only ever accumulates, independent of locking) and [`QUEUE_LOCK_BIT`]
+/// (guards an unrelated, orthogonal spinlock).
+const LOCK_MASK: usize = WRITER_BIT | POISONED_BIT | READER_MASK;
+
+/// An atomic cell encoding reader/writer state as a single word: the top
+/// bits are a writer flag, a poison flag, a waiter-list lock bit and an
+/// initialized flag, and the rest count active readers — so two callers racing on [`acquire_read`]/[`acquire_write`]
+/// can never both believe they hold incompatible access.
+///
+/// [`acquire_read`]: StateCell::acquire_read
+/// [`acquire_write`]: StateCell::acquire_write
 pub struct StateCell {
-    inner: UnsafeCell<State>,
+    inner: AtomicUsize,
 }
 
 impl StateCell {
-    pub const fn new(data: State) -> Self {
+    /// `initialized` should be `true` iff the data is already [`Data::Init`]
+    /// (i.e. constructed via [`LazyExclusive::new`], not [`new_with`]).
+    ///
+    /// [`new_with`]: LazyExclusive::new_with
+    pub const fn new(initialized: bool) -> Self {
         Self {
-            inner: UnsafeCell::new(data),
+            inner: AtomicUsize::new(if initialized { INIT_BIT } else { 0 }),
         }
     }
 
-    pub const fn get(&self) -> State {
-        // SAFETY: self.inner.get() is never an invalid pointer
-        unsafe { *self.inner.get() }
+    /// Relaxed load of a coarse [`State`]. Doesn't synchronize with anything;
+    /// use [`acquire_read`]/[`acquire_write`] to actually take the lock.
+    ///
+    /// [`acquire_read`]: StateCell::acquire_read
+    /// [`acquire_write`]: StateCell::acquire_write
+    pub fn get(&self) -> State {
+        let raw = self.inner.load(Ordering::Relaxed);
+        if raw & WRITER_BIT != 0 {
+            State::Locked
+        } else if raw & POISONED_BIT != 0 {
+            State::Poisoned
+        } else if raw & READER_MASK != 0 {
+            State::ReadLocked
+        } else {
+            State::Unlocked
+        }
+    }
+
+    /// Try to take the exclusive writer lock. Succeeds only if there are no
+    /// readers, no writer, and no poison.
+    fn acquire_write(&self) -> bool {
+        self.inner
+            .fetch_update(Ordering::Acquire, Ordering::Relaxed, |v| {
+                (v & LOCK_MASK == 0).then_some(v | WRITER_BIT)
+            })
+            .is_ok()
+    }
+
+    /// Try to take the writer lock to recover a poisoned handle. Succeeds
+    /// only if the data is poisoned and nothing else holds it.
+    fn acquire_write_poisoned(&self) -> bool {
+        self.inner
+            .fetch_update(Ordering::Acquire, Ordering::Relaxed, |v| {
+                (v & LOCK_MASK == POISONED_BIT).then_some((v & !LOCK_MASK) | WRITER_BIT)
+            })
+            .is_ok()
+    }
+
+    /// Release the writer lock, leaving the data poisoned if `poisoned`.
+    /// Marks the data as initialized, since a writer always forces it
+    /// before handing out a guard.
+    fn release_write(&self, poisoned: bool) {
+        self.inner
+            .fetch_update(Ordering::Release, Ordering::Relaxed, |v| {
+                Some((v & QUEUE_LOCK_BIT) | INIT_BIT | if poisoned { POISONED_BIT } else { 0 })
+            })
+            .expect("the closure always returns Some");
+    }
+
+    /// Release the writer lock after a panicking initializer, poisoning the
+    /// data without marking it as initialized. Unlike [`release_write`],
+    /// which always sets [`INIT_BIT`] because a real guard is only ever
+    /// handed out once `force` has completed, this is for `force`'s
+    /// `PoisonOnUnwind` bomb: `ensure_init` unwound, so `Data` is still
+    /// `Data::Uninit`, and setting `INIT_BIT` here would let
+    /// `acquire_read`/`try_read` skip `force` and later hit
+    /// `Data::Uninit` where only `Data::Init` is expected.
+    ///
+    /// [`release_write`]: StateCell::release_write
+    fn release_write_uninit_poisoned(&self) {
+        self.inner
+            .fetch_update(Ordering::Release, Ordering::Relaxed, |v| {
+                Some((v & QUEUE_LOCK_BIT) | POISONED_BIT)
+            })
+            .expect("the closure always returns Some");
+    }
+
+    /// Try to transition `Poisoned` -> `Unlocked`. Returns `true` if this
+    /// call cleared the poison; a no-op (returning `false`) if the data
+    /// wasn't poisoned.
+    fn clear_poison(&self) -> bool {
+        self.inner
+            .fetch_update(Ordering::Release, Ordering::Relaxed, |v| {
+                (v & LOCK_MASK == POISONED_BIT).then_some(v & (QUEUE_LOCK_BIT | INIT_BIT))
+            })
+            .is_ok()
+    }
+
+    /// Try to add a reader. Succeeds only if there's no writer, no poison,
+    /// and the data is already initialized — a first-ever access has to go
+    /// through [`acquire_write`] instead, so exactly one caller forces the
+    /// data (see [`finish_init`]) instead of racing.
+    ///
+    /// [`acquire_write`]: StateCell::acquire_write
+    /// [`finish_init`]: StateCell::finish_init
+    fn acquire_read(&self) -> bool {
+        self.inner
+            .fetch_update(Ordering::Acquire, Ordering::Relaxed, |v| {
+                (v & (WRITER_BIT | POISONED_BIT) == 0 && v & INIT_BIT != 0).then_some(v + 1)
+            })
+            .is_ok()
+    }
+
+    /// Remove one reader.
+    fn release_read(&self) {
+        self.inner.fetch_sub(1, Ordering::Release);
+    }
+
+    /// Downgrade a just-forced writer lock (taken by [`acquire_write`] to
+    /// perform first-time initialization) into a single reader.
+    ///
+    /// [`acquire_write`]: StateCell::acquire_write
+    fn finish_init(&self) {
+        self.inner
+            .fetch_update(Ordering::Release, Ordering::Relaxed, |v| {
+                Some((v & QUEUE_LOCK_BIT) | INIT_BIT | 1)
+            })
+            .expect("the closure always returns Some");
+    }
+
+    /// Spin until the waiter-list bit is ours. Used to guard pushes, pops,
+    /// and removals on the async waker queue.
+    #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+    fn lock_queue(&self) {
+        while self.inner.fetch_or(QUEUE_LOCK_BIT, Ordering::Acquire) & QUEUE_LOCK_BIT != 0 {
+            core::hint::spin_loop();
+        }
     }
 
-    pub fn set(&self, data: State) {
-        let _ = unsafe { core::mem::replace(&mut *self.inner.get(), data) };
+    #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+    fn unlock_queue(&self) {
+        self.inner.fetch_and(!QUEUE_LOCK_BIT, Ordering::Release);
+    }
+}
+
+/// The data held by a [`LazyExclusive`]: either the initializer that hasn't
+/// run yet, or the value it produced.
+enum Data<T> {
+    Uninit(fn() -> T),
+    Init(T),
+}
+
+impl<T> Data<T> {
+    /// Run the initializer if it hasn't run yet, turning `self` into `Init`.
+    fn ensure_init(&mut self) {
+        if let Self::Uninit(f) = self {
+            let f = *f;
+            *self = Self::Init(f());
+        }
     }
 }
 
 /// A container type like [`LazyLock`].
 /// Allows mutable access, but only one reference at a time.
+/// The inner value is constructed lazily: [`LazyExclusive::new_with`] only
+/// stores the initializer, which runs on the first access (e.g. [`get`] or
+/// [`wait`]). It runs again on a later access only if an earlier run
+/// panicked, which poisons the data.
+///
 /// ```rust
 /// use lazy_exclusive::LazyExclusive;
 ///
@@ -52,48 +289,129 @@ impl StateCell {
 /// assert!(LAZY.is_locked());
 /// ```
 ///
+/// ```rust
+/// use lazy_exclusive::LazyExclusive;
+///
+/// static LAZY: LazyExclusive<i32> = LazyExclusive::new_with(|| 123);
+/// // the closure hasn't run yet; it only runs once `get` is called
+/// let lock = LAZY.get().unwrap();
+/// assert_eq!(*lock, 123);
+/// ```
+///
 /// [`LazyLock`]: std::sync::LazyLock
+/// [`get`]: LazyExclusive::get
+/// [`wait`]: LazyExclusive::wait
 pub struct LazyExclusive<T> {
     state: StateCell,
-    data: UnsafeCell<T>,
-    #[cfg(feature = "use-locks")]
+    data: UnsafeCell<Data<T>>,
+    #[cfg(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"))]
     lock: Lock,
+    #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+    waiters: WakerQueue,
 }
 
-unsafe impl<T> Send for LazyExclusive<T> {}
+// Bounded on `T: Send`, same as `std::sync::Mutex`: a `LazyExclusive<T>` can
+// be moved to another thread and then hand out a `T` (via `get`/`into_inner`)
+// that was constructed on the original thread, so `T` itself has to be safe
+// to transfer between threads. Without this bound, `LazyExclusive<Rc<i32>>`
+// could be sent across threads and its `Rc` cloned/dropped there, racing the
+// non-atomic refcount `Rc: !Send` exists to protect.
+unsafe impl<T: Send> Send for LazyExclusive<T> {}
+// Sound for any `T` on its own: the state machine only ever hands out one
+// `Mut` (`&mut T`) at a time, so sharing a `&LazyExclusive<T>` across
+// threads never lets two threads observe `T` concurrently through `Mut`
+// alone. `read`/`try_read` (and `Debug`/`Clone`, which go through
+// `try_read`) are the exception -- they can hand `&T` to several threads
+// at once -- so those are bounded on `T: Sync` individually instead of
+// requiring it here for every caller.
 unsafe impl<T> Sync for LazyExclusive<T> {}
 
 pub struct Mut<'a, T> {
     source: &'a LazyExclusive<T>,
+    #[cfg(feature = "mcs-locks")]
+    node: Box<Node>,
+    /// Whether the data was already poisoned when this handle was created.
+    /// If so, [`Drop`] restores [`State::Poisoned`] instead of unlocking, so
+    /// poisoning persists until [`LazyExclusive::clear_poison`] is called.
+    poisoned: bool,
+}
+
+/// Why [`try_lock`]/[`lock`] failed to hand out a [`Mut`] outright.
+///
+/// [`try_lock`]: LazyExclusive::try_lock
+/// [`lock`]: LazyExclusive::lock
+pub enum LockError<'a, T> {
+    /// A handle already exists.
+    WouldBlock,
+    /// A previous handle panicked while holding the data. A guard onto the
+    /// (possibly inconsistent) value is included so the caller can inspect
+    /// or repair it; see [`LazyExclusive::clear_poison`] to lift the poison
+    /// afterwards.
+    Poisoned(Mut<'a, T>),
+}
+
+impl<'a, T> LockError<'a, T> {
+    /// Recover the guard carried by [`Poisoned`], if there is one.
+    ///
+    /// [`Poisoned`]: LockError::Poisoned
+    pub fn into_inner(self) -> Option<Mut<'a, T>> {
+        match self {
+            Self::WouldBlock => None,
+            Self::Poisoned(guard) => Some(guard),
+        }
+    }
+}
+
+impl<T> Debug for LockError<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WouldBlock => f.write_str("WouldBlock"),
+            Self::Poisoned(_) => f.write_str("Poisoned(..)"),
+        }
+    }
 }
 
 impl<T> Mut<'_, T> {
-    const fn inner(&mut self) -> &mut T {
+    fn inner(&mut self) -> &mut T {
         unsafe {
-            self.source
+            match self
+                .source
                 .data
                 .get()
                 .as_mut()
                 .expect("source.data is never a null pointer")
+            {
+                Data::Init(t) => t,
+                Data::Uninit(_) => {
+                    unreachable!("data is forced to Init before a Mut is handed out")
+                }
+            }
         }
     }
 }
 
 impl<T> Drop for Mut<'_, T> {
     fn drop(&mut self) {
-        self.source.state.set(State::Unlocked);
-        #[cfg(feature = "use-locks")]
+        #[cfg(feature = "std")]
+        let panicked = {
+            extern crate std;
+            std::thread::panicking()
+        };
+        #[cfg(not(feature = "std"))]
+        let panicked = false;
+
+        self.source.state.release_write(self.poisoned || panicked);
+
+        #[cfg(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"))]
         {
+            #[cfg(feature = "mcs-locks")]
+            self.source.lock.unlock(&self.node);
+            #[cfg(not(feature = "mcs-locks"))]
             self.source.lock.unlock();
-
-            #[cfg(feature = "std")]
-            {
-                extern crate std;
-                if std::thread::panicking() {
-                    self.source.state.set(State::Poisoned);
-                }
-            }
         }
+
+        #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+        self.source.wake_next();
     }
 }
 
@@ -102,11 +420,18 @@ impl<T> Deref for Mut<'_, T> {
 
     fn deref(&self) -> &Self::Target {
         unsafe {
-            self.source
+            match self
+                .source
                 .data
                 .get()
                 .as_ref()
                 .expect("source.data is never a null pointer")
+            {
+                Data::Init(t) => t,
+                Data::Uninit(_) => {
+                    unreachable!("data is forced to Init before a Mut is handed out")
+                }
+            }
         }
     }
 }
@@ -129,93 +454,752 @@ impl<T> DerefMut for Mut<'_, T> {
     }
 }
 
+/// A shared handle from [`LazyExclusive::read`]. Unlike [`Mut`], any number
+/// of [`Ref`]s can be held at once; there's no [`DerefMut`] impl, since
+/// nothing here guarantees exclusivity.
+pub struct Ref<'a, T> {
+    source: &'a LazyExclusive<T>,
+}
+
+impl<T> Drop for Ref<'_, T> {
+    fn drop(&mut self) {
+        self.source.state.release_read();
+
+        #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+        self.source.wake_next();
+    }
+}
+
+impl<T> Deref for Ref<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            match self
+                .source
+                .data
+                .get()
+                .as_ref()
+                .expect("source.data is never a null pointer")
+            {
+                Data::Init(t) => t,
+                Data::Uninit(_) => {
+                    unreachable!("data is forced before a Ref is handed out")
+                }
+            }
+        }
+    }
+}
+
+impl<T> AsRef<T> for Ref<'_, T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
 impl<T> LazyExclusive<T> {
     pub const fn new(data: T) -> Self {
-        let data = UnsafeCell::new(data);
-        let state = StateCell::new(State::Unlocked);
+        Self {
+            state: StateCell::new(true),
+            data: UnsafeCell::new(Data::Init(data)),
+            #[cfg(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"))]
+            lock: Lock::new(),
+            #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+            waiters: WakerQueue::new(),
+        }
+    }
 
-        #[cfg(not(feature = "use-locks"))]
-        return Self { state, data };
-        #[cfg(feature = "use-locks")]
+    /// Create a [`LazyExclusive`] that defers construction of `T` until the
+    /// first handle is acquired via [`get`] or [`wait`]. `f` completes at most
+    /// once; if it panics, the data is poisoned and `f` runs again on the next
+    /// access after [`clear_poison`].
+    ///
+    /// [`clear_poison`]: LazyExclusive::clear_poison
+    ///
+    /// [`get`]: LazyExclusive::get
+    /// [`wait`]: LazyExclusive::wait
+    pub const fn new_with(f: fn() -> T) -> Self {
         Self {
-            state,
-            data,
+            state: StateCell::new(false),
+            data: UnsafeCell::new(Data::Uninit(f)),
+            #[cfg(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"))]
             lock: Lock::new(),
+            #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+            waiters: WakerQueue::new(),
         }
     }
 
-    /// Get a handle to the inner data. Returns [`None`] if a handle already exists
+    /// Run the initializer if the data hasn't been constructed yet. Always
+    /// called while holding the writer bit (via [`StateCell::acquire_write`]
+    /// or [`StateCell::acquire_write_poisoned`]), whether for a real
+    /// [`Mut`]/[`Ref`] or just to force first-time init before downgrading
+    /// to a reader.
+    fn force(&self) {
+        // If the initializer panics, unwinding out of `ensure_init` would
+        // otherwise leave the writer bit held forever with no poison bit
+        // set: no `Mut` was ever constructed, so `Drop for Mut`'s
+        // `release_write` never runs, and every later caller sees
+        // `State::Locked` permanently with no `clear_poison` recovery path.
+        // This bomb poisons and releases the writer bit on unwind instead;
+        // it's defused by `forget` once `ensure_init` returns normally. It
+        // must not claim `INIT_BIT` like a normal `release_write` would:
+        // `ensure_init` never completed, so `Data` is still `Data::Uninit`,
+        // and `INIT_BIT` would wrongly tell `acquire_read`/`try_read` it's
+        // safe to skip `force` and read it directly.
+        struct PoisonOnUnwind<'a>(&'a StateCell);
+
+        impl Drop for PoisonOnUnwind<'_> {
+            fn drop(&mut self) {
+                self.0.release_write_uninit_poisoned();
+            }
+        }
+
+        let bomb = PoisonOnUnwind(&self.state);
+        unsafe {
+            (*self.data.get()).ensure_init();
+        }
+        core::mem::forget(bomb);
+    }
+
+    /// Like [`force`], but for callers that already hold the backend lock
+    /// (`try_get`/`try_lock`/`wait`/`lock`): if the initializer panics,
+    /// also release that lock, the same way [`force`] itself already
+    /// releases and poisons the atomic state, since `Drop for Mut` never
+    /// runs to release it otherwise.
+    ///
+    /// [`force`]: LazyExclusive::force
+    #[cfg(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"))]
+    fn force_holding_backend_lock(&self, #[cfg(feature = "mcs-locks")] node: &Node) {
+        struct UnlockOnUnwind<'a, T> {
+            source: &'a LazyExclusive<T>,
+            #[cfg(feature = "mcs-locks")]
+            node: &'a Node,
+        }
+
+        impl<T> Drop for UnlockOnUnwind<'_, T> {
+            fn drop(&mut self) {
+                #[cfg(feature = "mcs-locks")]
+                self.source.lock.unlock(self.node);
+                #[cfg(not(feature = "mcs-locks"))]
+                self.source.lock.unlock();
+            }
+        }
+
+        #[cfg(feature = "mcs-locks")]
+        let guard = UnlockOnUnwind { source: self, node };
+        #[cfg(not(feature = "mcs-locks"))]
+        let guard = UnlockOnUnwind { source: self };
+
+        self.force();
+        core::mem::forget(guard);
+    }
+
+    /// Get a handle to the inner data. Returns [`None`] if a handle already exists.
+    ///
+    /// Same as [`try_get`]; kept for backwards compatibility.
+    ///
+    /// [`try_get`]: LazyExclusive::try_get
     pub fn get(&self) -> Option<Mut<'_, T>> {
-        match self.state.get() {
-            State::Unlocked => {
-                self.state.set(State::Locked);
-                #[cfg(feature = "use-locks")]
-                self.lock.lock();
-                Some(Mut { source: self })
+        self.try_get()
+    }
+
+    /// Try to get a handle to the inner data without blocking. Returns
+    /// [`None`] if a handle already exists.
+    pub fn try_get(&self) -> Option<Mut<'_, T>> {
+        // Take the backend lock before the atomic state, same order as
+        // `wait`/`lock`. Doing it the other way around (state first,
+        // backend lock second) is a lock-order inversion: a `wait` caller
+        // can hold the backend lock while spinning on the state to drain
+        // readers, and if this call won the state race first it would then
+        // block on the backend lock forever, while `wait` can never win the
+        // state back from under it. A non-blocking `try_lock` on the
+        // backend keeps this call itself non-blocking too.
+        #[cfg(feature = "mcs-locks")]
+        let node = Box::new(Node::new());
+        #[cfg(feature = "mcs-locks")]
+        if !self.lock.try_lock(&node) {
+            return None;
+        }
+        #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+        if !self.lock.try_lock() {
+            return None;
+        }
+
+        if self.state.acquire_write() {
+            #[cfg(feature = "mcs-locks")]
+            self.force_holding_backend_lock(&node);
+            #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+            self.force_holding_backend_lock();
+            #[cfg(not(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks")))]
+            self.force();
+
+            #[cfg(feature = "mcs-locks")]
+            {
+                Some(Mut {
+                    source: self,
+                    node,
+                    poisoned: false,
+                })
+            }
+            #[cfg(not(feature = "mcs-locks"))]
+            {
+                Some(Mut {
+                    source: self,
+                    poisoned: false,
+                })
+            }
+        } else {
+            #[cfg(feature = "mcs-locks")]
+            self.lock.unlock(&node);
+            #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+            self.lock.unlock();
+            None
+        }
+    }
+
+    /// The poison-aware counterpart to [`try_get`]: never blocks, and if the
+    /// data was poisoned by a panicking handle, still hands one back (via
+    /// [`LockError::Poisoned`]) instead of refusing outright.
+    ///
+    /// [`try_get`]: LazyExclusive::try_get
+    pub fn try_lock(&self) -> Result<Mut<'_, T>, LockError<'_, T>> {
+        // See `try_get` for why the backend lock comes first.
+        #[cfg(feature = "mcs-locks")]
+        let node = Box::new(Node::new());
+        #[cfg(feature = "mcs-locks")]
+        if !self.lock.try_lock(&node) {
+            return Err(LockError::WouldBlock);
+        }
+        #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+        if !self.lock.try_lock() {
+            return Err(LockError::WouldBlock);
+        }
+
+        if self.state.acquire_write() {
+            #[cfg(feature = "mcs-locks")]
+            self.force_holding_backend_lock(&node);
+            #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+            self.force_holding_backend_lock();
+            #[cfg(not(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks")))]
+            self.force();
+
+            #[cfg(feature = "mcs-locks")]
+            {
+                Ok(Mut {
+                    source: self,
+                    node,
+                    poisoned: false,
+                })
             }
-            _ => None,
+            #[cfg(not(feature = "mcs-locks"))]
+            {
+                Ok(Mut {
+                    source: self,
+                    poisoned: false,
+                })
+            }
+        } else if self.state.acquire_write_poisoned() {
+            #[cfg(feature = "mcs-locks")]
+            self.force_holding_backend_lock(&node);
+            #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+            self.force_holding_backend_lock();
+            #[cfg(not(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks")))]
+            self.force();
+
+            #[cfg(feature = "mcs-locks")]
+            {
+                Err(LockError::Poisoned(Mut {
+                    source: self,
+                    node,
+                    poisoned: true,
+                }))
+            }
+            #[cfg(not(feature = "mcs-locks"))]
+            {
+                Err(LockError::Poisoned(Mut {
+                    source: self,
+                    poisoned: true,
+                }))
+            }
+        } else {
+            #[cfg(feature = "mcs-locks")]
+            self.lock.unlock(&node);
+            #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+            self.lock.unlock();
+            Err(LockError::WouldBlock)
+        }
+    }
+
+    /// Reset a poisoned lock back to [`State::Unlocked`]. A no-op if the
+    /// data isn't currently poisoned.
+    pub fn clear_poison(&self) {
+        if self.state.clear_poison() {
+            #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+            self.wake_next();
         }
     }
 
-    /// Set the inner value to [`new_value`]. Panics if the data is already locked
+    /// Set the inner value to [`new_value`]. Panics if the data is already
+    /// locked, read-locked, or poisoned -- including when a [`wait`]/[`lock`]
+    /// caller is merely queued on the backend lock, still spinning for
+    /// readers to drain before it ever sets the writer bit.
+    ///
+    /// [`wait`]: LazyExclusive::wait
+    /// [`lock`]: LazyExclusive::lock
     pub fn swap(&self, new_value: T) {
-        assert_eq!(self.state.get(), State::Unlocked);
+        // Take the backend lock before touching the atomic state below, the
+        // same way `try_get`/`try_lock` take it before the atomic state.
+        // Without this, a `wait`/`lock` caller could already hold (or, for
+        // MCS, be queued on) the backend lock while merely spinning on the
+        // atomic state -- invisible to the `acquire_write` check below,
+        // since it only reflects the reader/writer bits, not backend-lock
+        // queuing -- and releasing without a proper hand-off would hand out
+        // two writers or strand that caller forever.
+        #[cfg(feature = "mcs-locks")]
+        let node = Box::new(Node::new());
+        #[cfg(feature = "mcs-locks")]
+        assert!(self.lock.try_lock(&node), "the data is locked");
+        #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+        assert!(self.lock.try_lock(), "the data is locked");
+
+        if !self.state.acquire_write() {
+            #[cfg(feature = "mcs-locks")]
+            self.lock.unlock(&node);
+            #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+            self.lock.unlock();
+            panic!("the data is locked");
+        }
         unsafe {
-            let t = self.data.get().as_mut().unwrap();
-            *t = new_value;
-            self.state.set(State::Unlocked);
+            *self.data.get() = Data::Init(new_value);
+        }
+        self.state.release_write(false);
+
+        // Release via the normal hand-off path (`unlock`): a `wait`/`lock`
+        // caller blocked inside the OS primitive (`use-locks`) is woken by
+        // it, and for MCS a waiter may have linked its node onto ours while
+        // we held the lock above (see the comment at the top of this
+        // function), which `unlock` hands the lock to via `node.next`
+        // instead of stranding it.
+        #[cfg(feature = "mcs-locks")]
+        self.lock.unlock(&node);
+        #[cfg(all(not(feature = "mcs-locks"), any(feature = "use-locks", feature = "spin-locks")))]
+        self.lock.unlock();
+
+        #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+        self.wake_next();
+    }
 
-            #[cfg(feature = "use-locks")]
-            self.lock.reset();
+    /// Wake the next queued `lock_async` waiter, if any.
+    #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+    fn wake_next(&self) {
+        self.state.lock_queue();
+        let waker = unsafe { self.waiters.pop() };
+        self.state.unlock_queue();
+        if let Some(waker) = waker {
+            waker.wake();
         }
     }
 
-    pub const fn get_state(&self) -> State {
+    pub fn get_state(&self) -> State {
         self.state.get()
     }
 
-    /// Wait for the data to unlock and return a new handle
-    #[cfg(feature = "use-locks")]
+    /// Wait for the data to unlock and return a new handle. Blocks until no
+    /// writer and no readers remain; panics if the data is poisoned (see
+    /// [`lock`] to recover a poisoned handle instead).
+    ///
+    /// [`lock`]: LazyExclusive::lock
+    #[cfg(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"))]
     pub fn wait(&self) -> Mut<'_, T> {
+        #[cfg(feature = "mcs-locks")]
+        let node = {
+            let node = Box::new(Node::new());
+            self.lock.lock(&node);
+            node
+        };
+        #[cfg(not(feature = "mcs-locks"))]
+        self.lock.lock();
+
+        // The assert below can panic while we're still holding the backend
+        // lock above; without this guard that would wedge the backend lock
+        // forever, the same way a panicking initializer would without
+        // `force_holding_backend_lock`'s bomb -- `clear_poison` resets the
+        // atomic state, but every later `wait`/`lock` caller blocks trying
+        // to take this same backend lock first.
+        struct UnlockOnUnwind<'a, T> {
+            source: &'a LazyExclusive<T>,
+            #[cfg(feature = "mcs-locks")]
+            node: &'a Node,
+        }
+
+        impl<T> Drop for UnlockOnUnwind<'_, T> {
+            fn drop(&mut self) {
+                #[cfg(feature = "mcs-locks")]
+                self.source.lock.unlock(self.node);
+                #[cfg(not(feature = "mcs-locks"))]
+                self.source.lock.unlock();
+            }
+        }
+
+        #[cfg(feature = "mcs-locks")]
+        let guard = UnlockOnUnwind { source: self, node: &node };
+        #[cfg(not(feature = "mcs-locks"))]
+        let guard = UnlockOnUnwind { source: self };
+
+        // The backend lock above only serializes against other writers;
+        // readers don't take it, so there may still be some draining.
+        while !self.state.acquire_write() {
+            assert!(!self.is_poisoned(), "The data was poisoned");
+            core::hint::spin_loop();
+        }
+        core::mem::forget(guard);
+
+        #[cfg(feature = "mcs-locks")]
+        self.force_holding_backend_lock(&node);
+        #[cfg(not(feature = "mcs-locks"))]
+        self.force_holding_backend_lock();
+
+        #[cfg(feature = "mcs-locks")]
+        return Mut {
+            source: self,
+            node,
+            poisoned: false,
+        };
+        #[cfg(not(feature = "mcs-locks"))]
+        Mut {
+            source: self,
+            poisoned: false,
+        }
+    }
+
+    /// Like [`wait`], but relaxes with `R` between attempts to take the
+    /// backend lock instead of always busy-spinning via [`Spin`] -- e.g.
+    /// pass [`Yield`] to yield the OS thread between attempts.
+    ///
+    /// [`wait`]: LazyExclusive::wait
+    #[cfg(all(feature = "spin-locks", not(feature = "mcs-locks")))]
+    pub fn wait_with<R: Relax>(&self) -> Mut<'_, T> {
+        self.lock.lock_with::<R>();
+
+        // See `wait` for why this guard is needed: the assert below can
+        // panic while we're still holding the backend lock above, and
+        // without releasing it here that would wedge the lock forever.
+        struct UnlockOnUnwind<'a, T>(&'a LazyExclusive<T>);
+
+        impl<T> Drop for UnlockOnUnwind<'_, T> {
+            fn drop(&mut self) {
+                self.0.lock.unlock();
+            }
+        }
+
+        let guard = UnlockOnUnwind(self);
+
+        // The backend lock above only serializes against other writers;
+        // readers don't take it, so there may still be some draining.
+        while !self.state.acquire_write() {
+            assert!(!self.is_poisoned(), "The data was poisoned");
+            R::relax();
+        }
+        core::mem::forget(guard);
+
+        self.force_holding_backend_lock();
+
+        Mut {
+            source: self,
+            poisoned: false,
+        }
+    }
+
+    /// The poison-aware counterpart to [`wait`]: blocks until a handle can
+    /// be obtained, returning `Err(`[`LockError::Poisoned`]`)` instead of
+    /// panicking if the data was poisoned.
+    ///
+    /// [`wait`]: LazyExclusive::wait
+    #[cfg(any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"))]
+    pub fn lock(&self) -> Result<Mut<'_, T>, LockError<'_, T>> {
+        #[cfg(feature = "mcs-locks")]
+        let node = {
+            let node = Box::new(Node::new());
+            self.lock.lock(&node);
+            node
+        };
+        #[cfg(not(feature = "mcs-locks"))]
         self.lock.lock();
-        assert_eq!(self.state.get(), State::Unlocked, "The data was poisoned");
-        self.state.set(State::Locked);
-        Mut { source: self }
+
+        // As in `wait`, the backend lock only serializes writers; spin until
+        // any draining readers are gone before taking the data itself.
+        let poisoned = loop {
+            if self.state.acquire_write() {
+                break false;
+            }
+            if self.state.acquire_write_poisoned() {
+                break true;
+            }
+            core::hint::spin_loop();
+        };
+        #[cfg(feature = "mcs-locks")]
+        self.force_holding_backend_lock(&node);
+        #[cfg(not(feature = "mcs-locks"))]
+        self.force_holding_backend_lock();
+
+        #[cfg(feature = "mcs-locks")]
+        let guard = Mut {
+            source: self,
+            node,
+            poisoned,
+        };
+        #[cfg(not(feature = "mcs-locks"))]
+        let guard = Mut {
+            source: self,
+            poisoned,
+        };
+
+        if poisoned {
+            Err(LockError::Poisoned(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Like [`lock`], but relaxes with `R` between attempts to take the
+    /// backend lock instead of always busy-spinning via [`Spin`] -- e.g.
+    /// pass [`Yield`] to yield the OS thread between attempts.
+    ///
+    /// [`lock`]: LazyExclusive::lock
+    #[cfg(all(feature = "spin-locks", not(feature = "mcs-locks")))]
+    pub fn lock_with<R: Relax>(&self) -> Result<Mut<'_, T>, LockError<'_, T>> {
+        self.lock.lock_with::<R>();
+
+        // As in `lock`, the backend lock only serializes writers; spin until
+        // any draining readers are gone before taking the data itself.
+        let poisoned = loop {
+            if self.state.acquire_write() {
+                break false;
+            }
+            if self.state.acquire_write_poisoned() {
+                break true;
+            }
+            R::relax();
+        };
+        self.force_holding_backend_lock();
+
+        let guard = Mut {
+            source: self,
+            poisoned,
+        };
+
+        if poisoned {
+            Err(LockError::Poisoned(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// The async counterpart to [`wait`]: suspends the calling task instead
+    /// of blocking the OS thread, so it can be awaited from inside an async
+    /// runtime without tying up a worker thread.
+    ///
+    /// [`wait`]: LazyExclusive::wait
+    #[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+    pub async fn lock_async(&self) -> Mut<'_, T> {
+        LockFuture {
+            source: self,
+            node: WakerNode::new(),
+            queued: false,
+            _pin: PhantomPinned,
+        }
+        .await
     }
 
     pub fn into_inner(self) -> T {
         match self.state.get() {
-            State::Unlocked => self.data.into_inner(),
+            State::Unlocked => match self.data.into_inner() {
+                Data::Init(t) => t,
+                Data::Uninit(f) => f(),
+            },
             State::Locked => panic!("locked"),
+            State::ReadLocked => panic!("read-locked"),
             State::Poisoned => panic!("poisoned"),
         }
     }
 
-    pub const fn is_unlocked(&self) -> bool {
+    /// Like [`into_inner`], but returns the poisoned value as `Err` instead
+    /// of panicking.
+    ///
+    /// [`into_inner`]: LazyExclusive::into_inner
+    pub fn try_into_inner(self) -> Result<T, T> {
+        let current = self.state.get();
+        let poisoned = current == State::Poisoned;
+        assert!(
+            matches!(current, State::Unlocked | State::Poisoned),
+            "locked"
+        );
+
+        let value = match self.data.into_inner() {
+            Data::Init(t) => t,
+            Data::Uninit(f) => f(),
+        };
+
+        if poisoned {
+            Err(value)
+        } else {
+            Ok(value)
+        }
+    }
+
+    pub fn is_unlocked(&self) -> bool {
         matches!(self.state.get(), State::Unlocked)
     }
 
-    pub const fn is_locked(&self) -> bool {
+    /// Whether a writer currently holds the data exclusively.
+    pub fn is_locked(&self) -> bool {
         matches!(self.state.get(), State::Locked)
     }
 
-    pub const fn is_poisoned(&self) -> bool {
+    /// Whether one or more readers currently hold the data.
+    pub fn is_read_locked(&self) -> bool {
+        matches!(self.state.get(), State::ReadLocked)
+    }
+
+    pub fn is_poisoned(&self) -> bool {
         matches!(self.state.get(), State::Poisoned)
     }
 }
 
-impl<T: Debug> Debug for LazyExclusive<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let data: &dyn Debug = match self.state.get() {
-            State::Unlocked => unsafe { self.data.get().as_mut().expect("Should never fail") },
-            State::Locked => &"<locked>",
-            State::Poisoned => &"<poisoned>",
-        };
+impl<T: Sync> LazyExclusive<T> {
+    /// Get a shared handle to the inner data. Unlike [`get`], any number of
+    /// [`Ref`]s can be held at once; this only fails (returning [`None`]) if
+    /// a writer currently holds the data exclusively, or it's poisoned.
+    ///
+    /// Requires `T: Sync`: unlike [`Mut`], which only ever hands `&T`/`&mut
+    /// T` to one thread at a time, [`Ref`] lets multiple threads hold `&T`
+    /// concurrently, which is only sound if `T` allows that.
+    ///
+    /// [`get`]: LazyExclusive::get
+    pub fn read(&self) -> Option<Ref<'_, T>> {
+        self.try_read()
+    }
+
+    /// Same as [`read`]; kept alongside [`try_get`]/[`try_lock`] for naming
+    /// symmetry, since [`read`] never blocks either.
+    ///
+    /// [`read`]: LazyExclusive::read
+    /// [`try_get`]: LazyExclusive::try_get
+    /// [`try_lock`]: LazyExclusive::try_lock
+    pub fn try_read(&self) -> Option<Ref<'_, T>> {
+        if self.state.acquire_read() {
+            return Some(Ref { source: self });
+        }
+
+        // Not yet initialized (or contended for first access): briefly
+        // become the writer to force the data, so no other reader can
+        // observe it half-built, then downgrade to a single reader
+        // (ourselves). Fails like any other `try_*` call if a real writer
+        // holds it, it's poisoned, or another caller won this race instead.
+        if self.state.acquire_write() {
+            self.force();
+            self.state.finish_init();
+            return Some(Ref { source: self });
+        }
+
+        None
+    }
+}
+
+/// The future returned by [`LazyExclusive::lock_async`]. Queues itself on
+/// the source's waiter list while pending; [`Mut::drop`] pops and wakes the
+/// most recently queued waiter after releasing.
+#[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+struct LockFuture<'a, T> {
+    source: &'a LazyExclusive<T>,
+    node: WakerNode,
+    queued: bool,
+    _pin: PhantomPinned,
+}
+
+#[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+impl<'a, T> Future for LockFuture<'a, T> {
+    type Output = Mut<'a, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: we never move `self` or `self.node` out; `node`'s address
+        // is only handed to `waiters` while `queued` is true, and is always
+        // unlinked before it can become stale (here and in `Drop`).
+        let this = unsafe { self.get_unchecked_mut() };
+
+        if this.source.state.acquire_write() {
+            if this.queued {
+                this.source.state.lock_queue();
+                unsafe { this.source.waiters.remove(&this.node) };
+                this.source.state.unlock_queue();
+                this.queued = false;
+            }
+            this.source.force();
+            return Poll::Ready(Mut {
+                source: this.source,
+                poisoned: false,
+            });
+        }
+
+        // Register under the queue lock, re-checking `acquire_write` first so a
+        // release that happened between the check above and taking the
+        // queue lock can't be missed (the releaser also takes the queue
+        // lock, in `wake_next`, before handing off to a waiter).
+        this.source.state.lock_queue();
+        if this.source.state.acquire_write() {
+            if this.queued {
+                unsafe { this.source.waiters.remove(&this.node) };
+                this.queued = false;
+            }
+            this.source.state.unlock_queue();
+            this.source.force();
+            return Poll::Ready(Mut {
+                source: this.source,
+                poisoned: false,
+            });
+        }
+        // `this.queued` alone isn't enough: `wake_next` may have already
+        // popped this node (e.g. to wake it for a re-poll that then lost
+        // `acquire_write` to an unrelated `try_get`/`get`/`lock_async`
+        // caller), which unlinks it from `waiters` without clearing
+        // `queued`. Overwriting the waker in an unlinked node would orphan
+        // it, so check the node's actual link state under the queue lock.
+        if this.queued && unsafe { this.node.is_linked() } {
+            unsafe { this.node.set_waker(cx.waker().clone()) };
+        } else {
+            let node: *const WakerNode = &this.node;
+            unsafe { this.source.waiters.push(node, cx.waker().clone()) };
+            this.queued = true;
+        }
+        this.source.state.unlock_queue();
+        Poll::Pending
+    }
+}
+
+#[cfg(all(feature = "async-lock", not(feature = "mcs-locks")))]
+impl<T> Drop for LockFuture<'_, T> {
+    fn drop(&mut self) {
+        if self.queued {
+            self.source.state.lock_queue();
+            unsafe { self.source.waiters.remove(&self.node) };
+            self.source.state.unlock_queue();
+        }
+    }
+}
 
-        f.debug_struct("LazyExclusive")
-            .field("state", &self.state.get())
-            .field("data", data)
-            .finish()
+impl<T: Debug + Sync> Debug for LazyExclusive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Go through `try_read` rather than peeking at `data` directly: a
+        // bare `state.get()` check races with a concurrent writer forcing
+        // the data (e.g. another `clone()`), since both would read/write
+        // the same `UnsafeCell` with no synchronization between them.
+        let mut builder = f.debug_struct("LazyExclusive");
+        builder.field("state", &self.state.get());
+        match self.try_read() {
+            Some(guard) => builder.field("data", &*guard).finish(),
+            None if self.is_poisoned() => builder.field("data", &"<poisoned>").finish(),
+            None => builder.field("data", &"<locked>").finish(),
+        }
     }
 }
 
@@ -225,15 +1209,31 @@ impl<T> From<T> for LazyExclusive<T> {
     }
 }
 
-impl<T: Clone> Clone for LazyExclusive<T> {
+impl<T: Clone + Sync> Clone for LazyExclusive<T> {
     fn clone(&self) -> Self {
-        let data = match self.state.get() {
-            State::Unlocked => unsafe { self.data.get().as_ref().expect("Should never fail") },
-            State::Locked => panic!("locked"),
-            State::Poisoned => panic!("poisoned"),
+        // `try_read` takes care of synchronizing first-ever access (via a
+        // momentary writer lock) the same way `get`/`lock` do, so two
+        // concurrent clones (or a clone racing a `get`) can never both end
+        // up forcing the same `UnsafeCell` at once.
+        let guard = match self.try_read() {
+            Some(guard) => guard,
+            None if self.is_poisoned() => panic!("poisoned"),
+            None => panic!("locked"),
         };
 
-        Self::new(data.clone())
+        Self::new((*guard).clone())
+    }
+}
+
+impl<T: Clone> LazyExclusive<T> {
+    /// Like [`Clone::clone`], but returns `Err(`[`LockError::Poisoned`]`)`
+    /// instead of panicking if the data was poisoned, and `Err(`[`LockError::WouldBlock`]`)`
+    /// instead of panicking if a handle already exists.
+    pub fn try_clone(&self) -> Result<Self, LockError<'_, T>> {
+        match self.try_lock() {
+            Ok(guard) => Ok(Self::new((*guard).clone())),
+            Err(err) => Err(err),
+        }
     }
 }
 
@@ -245,6 +1245,8 @@ impl<T: Default> Default for LazyExclusive<T> {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "std")]
+    use crate::LockError;
     use crate::{LazyExclusive, State};
 
     #[test]
@@ -266,13 +1268,314 @@ mod tests {
         assert_eq!(*pointer, 1231);
     }
 
+    // `lock_test`, `spin_lock_test`, and `mcs_lock_test` exercise the exact
+    // same scenario -- a writer blocks, a waiter calls `wait()`, the waiter
+    // sees the write through once the writer drops -- once per backend-lock
+    // feature. Generate them from one body so the three can't drift apart
+    // from each other the way byte-for-byte copies tend to.
+    #[cfg(all(
+        any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks"),
+        feature = "std"
+    ))]
+    macro_rules! backend_lock_test {
+        ($name:ident) => {
+            #[test]
+            fn $name() {
+                extern crate std;
+                use crate::State;
+                use std::time::{Duration, Instant};
+
+                let start = Instant::now();
+                let five_seconds = Duration::from_secs(5);
+                static SHARED: LazyExclusive<i32> = LazyExclusive::new(120);
+                let mut lock = SHARED.get().unwrap();
+
+                std::thread::spawn(move || {
+                    *lock *= 2;
+                    std::thread::sleep(Duration::new(5, 0));
+                });
+
+                assert_eq!(SHARED.get_state(), State::Locked);
+                let new_lock = SHARED.wait();
+                assert_eq!(*new_lock, 120 * 2);
+                assert!(start.elapsed() >= five_seconds);
+            }
+        };
+    }
+
     #[cfg(all(feature = "use-locks", feature = "std"))]
+    backend_lock_test!(lock_test);
+
+    #[cfg(all(feature = "spin-locks", feature = "std"))]
+    backend_lock_test!(spin_lock_test);
+
+    #[cfg(all(feature = "spin-locks", feature = "std"))]
+    #[test]
+    fn spin_lock_try_get_does_not_deadlock_with_waiting_writer() {
+        extern crate std;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(1);
+        let reader = SHARED.read().unwrap();
+
+        // `wait` takes the backend lock before spinning on the state, so
+        // while the reader above is open it sits there holding the backend
+        // lock. A concurrent `try_get`/`try_lock` used to take the state
+        // first and the backend lock second -- the opposite order -- so if
+        // it won the state race here it would then block forever on the
+        // backend lock `wait` is holding, while `wait` could never win the
+        // state back. `try_get` now takes the backend lock first too, so it
+        // fails fast instead.
+        let (tx, rx) = mpsc::channel();
+        let waiter = std::thread::spawn(move || {
+            let _guard = SHARED.wait();
+            tx.send(()).unwrap();
+        });
+
+        let hammer = std::thread::spawn(|| {
+            for _ in 0..10_000 {
+                let _ = SHARED.try_get();
+            }
+        });
+
+        std::thread::sleep(Duration::from_millis(10));
+        drop(reader);
+
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("wait() should complete; a concurrent try_get() must not deadlock it");
+        waiter.join().unwrap();
+        hammer.join().unwrap();
+    }
+
+    #[cfg(all(feature = "spin-locks", not(feature = "mcs-locks"), feature = "std"))]
+    #[test]
+    fn wait_with_and_lock_with_honor_a_custom_relax() {
+        extern crate std;
+        use crate::{Spin, Yield};
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(1);
+
+        let guard = SHARED.wait_with::<Yield>();
+        assert_eq!(*guard, 1);
+        drop(guard);
+
+        let guard = SHARED.lock_with::<Spin>().unwrap();
+        assert_eq!(*guard, 1);
+        drop(guard);
+    }
+
+    #[cfg(all(feature = "spin-locks", feature = "std"))]
+    #[test]
+    fn lock_concurrent_callers_never_hold_overlapping_guards() {
+        extern crate std;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(0);
+        static LIVE: AtomicUsize = AtomicUsize::new(0);
+        static MAX_LIVE: AtomicUsize = AtomicUsize::new(0);
+
+        let handles: std::vec::Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    for _ in 0..200 {
+                        let guard = SHARED.lock().unwrap();
+                        let live = LIVE.fetch_add(1, Ordering::SeqCst) + 1;
+                        MAX_LIVE.fetch_max(live, Ordering::SeqCst);
+                        std::thread::yield_now();
+                        LIVE.fetch_sub(1, Ordering::SeqCst);
+                        drop(guard);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // `lock`'s original implementation (request chunk0-5) raced on a
+        // plain `get`+`set` instead of the CAS primitive every other entry
+        // point uses, so two callers could both believe they held the
+        // writer lock at once; this pins the invariant so a similar
+        // regression can't land silently again.
+        assert_eq!(MAX_LIVE.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(all(feature = "mcs-locks", feature = "std"))]
+    backend_lock_test!(mcs_lock_test);
+
+    #[cfg(all(feature = "mcs-locks", feature = "std"))]
+    #[test]
+    fn mcs_lock_try_get_does_not_deadlock_with_waiting_writer() {
+        extern crate std;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(1);
+        let reader = SHARED.read().unwrap();
+
+        // Same scenario as the spin-locks counterpart: `wait` holds the
+        // backend (MCS) lock while spinning for the reader to drain, so a
+        // `try_get` that still took the state first would risk deadlocking
+        // against it.
+        let (tx, rx) = mpsc::channel();
+        let waiter = std::thread::spawn(move || {
+            let _guard = SHARED.wait();
+            tx.send(()).unwrap();
+        });
+
+        let hammer = std::thread::spawn(|| {
+            for _ in 0..10_000 {
+                let _ = SHARED.try_get();
+            }
+        });
+
+        std::thread::sleep(Duration::from_millis(10));
+        drop(reader);
+
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("wait() should complete; a concurrent try_get() must not deadlock it");
+        waiter.join().unwrap();
+        hammer.join().unwrap();
+    }
+
+    #[cfg(all(feature = "mcs-locks", feature = "std"))]
+    #[test]
+    fn mcs_lock_swap_does_not_corrupt_a_queued_writer() {
+        extern crate std;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(1);
+        let reader = SHARED.read().unwrap();
+
+        // `wait` takes the MCS backend lock immediately (the queue was
+        // empty) and then spins on the atomic state for the reader above
+        // to drain. While it's spinning, `swap`'s own backend-lock
+        // acquisition must see the queue non-empty and back off instead of
+        // resetting `tail` out from under the queued node.
+        let (tx, rx) = mpsc::channel();
+        let waiter = std::thread::spawn(move || {
+            let guard = SHARED.wait();
+            tx.send(()).unwrap();
+            drop(guard);
+        });
+
+        std::thread::sleep(Duration::from_millis(10));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| SHARED.swap(99)));
+        assert!(
+            result.is_err(),
+            "swap should refuse to run while a writer is queued on the backend lock"
+        );
+
+        drop(reader);
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("wait() should still complete normally after swap backed off");
+        waiter.join().unwrap();
+    }
+
+    #[cfg(all(feature = "mcs-locks", feature = "std"))]
+    #[test]
+    fn mcs_lock_swap_does_not_strand_a_concurrently_queued_waiter() {
+        extern crate std;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::{Duration, Instant};
+
+        // Unlike `mcs_lock_swap_does_not_corrupt_a_queued_writer`, which only
+        // covers a waiter that's already fully queued before `swap` starts
+        // (so `swap`'s own `try_lock` fails outright), this races a `wait`
+        // loop against a `swap` loop so a waiter can link its node onto
+        // `swap`'s node *during* `swap`'s own critical section. `swap` must
+        // hand the backend lock off to that waiter (`unlock`) rather than
+        // `reset`ting it, or the waiter is stranded spinning forever.
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(0);
+        static DONE: AtomicUsize = AtomicUsize::new(0);
+
+        let swapper = std::thread::spawn(|| {
+            // `swap` legitimately panics ("the data is locked") whenever it
+            // loses the non-blocking `try_lock` race against a queued
+            // waiter, which is expected under this much contention and not
+            // what's under test here, so swallow just that outcome.
+            for i in 0..200_000 {
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| SHARED.swap(i)));
+            }
+        });
+        let waiter = std::thread::spawn(|| {
+            for _ in 0..5_000 {
+                let _guard = SHARED.wait();
+                DONE.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let start = Instant::now();
+        loop {
+            if waiter.is_finished() && swapper.is_finished() {
+                break;
+            }
+            assert!(
+                start.elapsed() <= Duration::from_secs(20),
+                "deadlock suspected: waiter completed {} of 5000 iterations before stalling",
+                DONE.load(Ordering::SeqCst)
+            );
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        swapper.join().unwrap();
+        waiter.join().unwrap();
+    }
+
+    #[cfg(all(feature = "async-lock", feature = "std", not(feature = "mcs-locks")))]
     #[test]
-    fn lock_test() {
+    fn async_lock_test() {
         extern crate std;
-        use crate::State;
+        use core::future::Future;
+        use core::pin::Pin;
+        use core::task::{Context, Poll, Waker};
+        use std::sync::{Arc, Condvar, Mutex};
+        use std::task::Wake;
         use std::time::{Duration, Instant};
 
+        struct ThreadWaker {
+            signaled: Mutex<bool>,
+            cv: Condvar,
+        }
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.wake_by_ref();
+            }
+
+            fn wake_by_ref(self: &Arc<Self>) {
+                *self.signaled.lock().unwrap() = true;
+                self.cv.notify_one();
+            }
+        }
+
+        // A minimal single-future executor: enough to prove `lock_async`
+        // actually suspends and gets woken, without pulling in a real one.
+        fn block_on<F: Future>(fut: F) -> F::Output {
+            let mut fut = fut;
+            // SAFETY: `fut` is shadowed and never moved again after this.
+            let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+            let state = Arc::new(ThreadWaker {
+                signaled: Mutex::new(false),
+                cv: Condvar::new(),
+            });
+            let waker = Waker::from(state.clone());
+            let mut cx = Context::from_waker(&waker);
+
+            loop {
+                if let Poll::Ready(value) = fut.as_mut().poll(&mut cx) {
+                    return value;
+                }
+                let mut signaled = state.signaled.lock().unwrap();
+                while !*signaled {
+                    signaled = state.cv.wait(signaled).unwrap();
+                }
+                *signaled = false;
+            }
+        }
+
         let start = Instant::now();
         let five_seconds = Duration::from_secs(5);
         static SHARED: LazyExclusive<i32> = LazyExclusive::new(120);
@@ -284,7 +1587,7 @@ mod tests {
         });
 
         assert_eq!(SHARED.get_state(), State::Locked);
-        let new_lock = SHARED.wait();
+        let new_lock = block_on(SHARED.lock_async());
         assert_eq!(*new_lock, 120 * 2);
         assert!(start.elapsed() >= five_seconds);
     }
@@ -297,6 +1600,250 @@ mod tests {
         assert_eq!(lazy.get_state(), State::Unlocked);
     }
 
+    #[test]
+    fn try_get_never_blocks() {
+        let lazy = LazyExclusive::new(1);
+        let first = lazy.try_get();
+        assert!(first.is_some());
+        assert!(lazy.try_get().is_none());
+        drop(first);
+        assert!(lazy.try_get().is_some());
+    }
+
+    #[test]
+    fn many_readers() {
+        let lazy = LazyExclusive::new(42);
+
+        let r1 = lazy.read().unwrap();
+        let r2 = lazy.read().unwrap();
+        assert_eq!(*r1, 42);
+        assert_eq!(*r2, 42);
+        assert!(lazy.is_read_locked());
+
+        // A writer can't jump in while readers are active...
+        assert!(lazy.try_get().is_none());
+
+        drop(r1);
+        assert!(lazy.is_read_locked());
+        drop(r2);
+        assert!(lazy.is_unlocked());
+
+        // ...but once they're all gone, it can.
+        let write = lazy.try_get().unwrap();
+        assert!(lazy.read().is_none());
+        drop(write);
+        assert!(lazy.read().is_some());
+    }
+
+    #[test]
+    fn read_forces_lazy_init_once() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn make() -> i32 {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            7
+        }
+
+        let lazy = LazyExclusive::new_with(make);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        let r1 = lazy.read().unwrap();
+        let r2 = lazy.read().unwrap();
+        assert_eq!(*r1, 7);
+        assert_eq!(*r2, 7);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn many_reader_threads() {
+        extern crate std;
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(10);
+
+        let handles: std::vec::Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    for _ in 0..1000 {
+                        if let Some(r) = SHARED.read() {
+                            assert_eq!(*r, 10);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(SHARED.is_unlocked());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn concurrent_clone_runs_initializer_once() {
+        extern crate std;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn make() -> i32 {
+            let n = CALLS.fetch_add(1, Ordering::SeqCst);
+            // Widen the race window so two threads are likely to overlap.
+            std::thread::yield_now();
+            n as i32
+        }
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new_with(make);
+
+        // `clone` is allowed to see the data as momentarily locked and
+        // panic (same contract as `get`), so retry past that; what must
+        // never happen is `make` running more than once.
+        let handles: std::vec::Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| loop {
+                    match std::panic::catch_unwind(|| SHARED.clone()) {
+                        Ok(clone) => return clone,
+                        Err(_) => std::thread::yield_now(),
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poison_and_recover() {
+        extern crate std;
+
+        let lazy = LazyExclusive::new(1);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut guard = lazy.get().unwrap();
+            *guard = 2;
+            panic!("simulated failure while holding the handle");
+        }));
+        assert!(result.is_err());
+        assert!(lazy.is_poisoned());
+
+        match lazy.try_lock() {
+            Err(LockError::Poisoned(mut guard)) => {
+                assert_eq!(*guard, 2);
+                *guard = 3;
+            }
+            _ => panic!("expected a poisoned handle"),
+        }
+        assert!(lazy.is_poisoned());
+
+        lazy.clear_poison();
+        assert!(lazy.is_unlocked());
+        assert_eq!(*lazy.get().unwrap(), 3);
+    }
+
+    #[cfg(all(
+        feature = "std",
+        any(feature = "use-locks", feature = "spin-locks", feature = "mcs-locks")
+    ))]
+    #[test]
+    fn wait_on_poisoned_data_releases_backend_lock_for_later_callers() {
+        extern crate std;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        static SHARED: LazyExclusive<i32> = LazyExclusive::new(1);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _guard = SHARED.get().unwrap();
+            panic!("simulated failure while holding the handle");
+        }));
+        assert!(result.is_err());
+        assert!(SHARED.is_poisoned());
+
+        // `wait` takes the backend lock, then panics on the poison assert
+        // below while still holding it. If that panic didn't release the
+        // backend lock, `clear_poison` would reset the atomic state but
+        // every later `wait` call would still block forever trying to take
+        // the wedged backend lock.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            SHARED.wait();
+        }));
+        assert!(result.is_err());
+
+        SHARED.clear_poison();
+        assert!(SHARED.is_unlocked());
+
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _guard = SHARED.wait();
+            tx.send(()).unwrap();
+        });
+
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("wait() after clear_poison() must not deadlock on the backend lock");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn panicking_initializer_poisons_instead_of_deadlocking() {
+        extern crate std;
+
+        fn make() -> i32 {
+            panic!("simulated failure during lazy init");
+        }
+
+        let lazy = LazyExclusive::new_with(make);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| lazy.get()));
+        assert!(result.is_err());
+
+        // Without poisoning `force`'s caller, this would stay `Locked`
+        // forever instead of `Poisoned` (no `Mut` was ever handed out to
+        // run `Drop for Mut`'s `release_write`), and every later call
+        // would return `None` with no way to ever recover.
+        assert!(lazy.is_poisoned());
+
+        lazy.clear_poison();
+        assert!(lazy.is_unlocked());
+
+        // The initializer still panics on a retry, but the lock itself
+        // isn't wedged: a plain `swap`, which never touches the
+        // initializer, can take the writer lock and replace the value.
+        lazy.swap(5);
+        assert_eq!(*lazy.get().unwrap(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_after_panicking_initializer_reforces_instead_of_trusting_init_bit() {
+        extern crate std;
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        static SHOULD_PANIC: AtomicBool = AtomicBool::new(true);
+        fn make() -> i32 {
+            if SHOULD_PANIC.swap(false, Ordering::SeqCst) {
+                panic!("simulated failure during lazy init");
+            }
+            7
+        }
+
+        let lazy = LazyExclusive::new_with(make);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| lazy.get()));
+        assert!(result.is_err());
+        lazy.clear_poison();
+
+        // `Data` is still `Data::Uninit` here: the panicking initializer
+        // never completed, so `force` never replaced it. If `clear_poison`
+        // wrongly left `INIT_BIT` set, `read`/`try_read` (and `Debug`,
+        // which goes through `try_read`) would skip `force` and hit
+        // `Data::Uninit` where only `Data::Init` is ever expected, instead
+        // of correctly re-forcing it here.
+        assert_eq!(*lazy.read().unwrap(), 7);
+        assert!(std::format!("{lazy:?}").contains('7'));
+    }
+
     #[test]
     fn clone() {
         let lazy = LazyExclusive::new(120);
@@ -304,4 +1851,23 @@ mod tests {
 
         assert_eq!(lazy.into_inner(), clone.into_inner());
     }
+
+    #[test]
+    fn lazy_init() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn make() -> i32 {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            42
+        }
+
+        let lazy = LazyExclusive::new_with(make);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+        assert_eq!(*lazy.get().unwrap(), 42);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+        assert_eq!(*lazy.get().unwrap(), 42);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
 }
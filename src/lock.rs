@@ -1,6 +1,10 @@
 #![allow(non_camel_case_types)]
 
-use core::{cell::UnsafeCell, ptr};
+use core::{
+    cell::UnsafeCell,
+    ptr,
+    sync::atomic::{AtomicU8, Ordering},
+};
 #[cfg(target_os = "windows")]
 type SRWLOCK = usize;
 
@@ -10,11 +14,13 @@ windows_link::link!("kernel32.dll" "system" fn InitializeSRWLock(lock: *mut SRWL
 windows_link::link!("kernel32.dll" "system" fn AcquireSRWLockExclusive(lock: *mut SRWLOCK));
 #[cfg(target_os = "windows")]
 windows_link::link!("kernel32.dll" "system" fn ReleaseSRWLockExclusive(lock: *mut SRWLOCK));
+#[cfg(target_os = "windows")]
+windows_link::link!("kernel32.dll" "system" fn TryAcquireSRWLockExclusive(lock: *mut SRWLOCK) -> u8);
 
 #[cfg(not(target_os = "windows"))]
 use libc::{
     pthread_mutex_destroy, pthread_mutex_init, pthread_mutex_lock, pthread_mutex_t,
-    pthread_mutex_unlock,
+    pthread_mutex_trylock, pthread_mutex_unlock,
 };
 
 enum LockState {
@@ -43,30 +49,30 @@ impl LockState {
     }
 }
 
-pub struct Lock(UnsafeCell<LockState>);
+/// `init_state` hasn't started building the OS primitive yet.
+const UNINIT: u8 = 0;
+/// Some caller is inside `init`, building the OS primitive; everyone else
+/// spins until it flips to [`INITIALIZED`].
+const INITIALIZING: u8 = 1;
+/// The OS primitive is built and `LockState` is safely readable.
+const INITIALIZED: u8 = 2;
+
+pub struct Lock {
+    /// Guards the one-time transition of `state` out of
+    /// `LockState::Uninitialized`: whoever wins the `UNINIT` -> `INITIALIZING`
+    /// CAS is the sole writer of `state` below, so two threads calling
+    /// `lock`/`try_lock`/`unlock` on a fresh instance can never both build
+    /// (and non-atomically store) their own OS mutex into the same cell.
+    init_state: AtomicU8,
+    state: UnsafeCell<LockState>,
+}
 
 impl Lock {
     pub const fn new() -> Self {
-        /*
-        #[cfg(not(target_os = "windows"))]
-        let data = unsafe {
-            let data = UnsafeCell::new([0_u8; LEN]);
-            let result = pthread_mutex_init(data.get(), ptr::null());
-            assert_eq!(
-                result, 0,
-                "Cannot initialize the mutex: `pthread_mutex_init` returned a non-zero value"
-            );
-            data
-        };
-        #[cfg(target_os = "windows")]
-        let data = unsafe {
-            let cell = UnsafeCell::new(0 as SRWLOCK);
-            InitializeSRWLock(cell.get());
-            cell
-        };
-        */
-
-        Self(UnsafeCell::new(LockState::Uninitialized))
+        Self {
+            init_state: AtomicU8::new(UNINIT),
+            state: UnsafeCell::new(LockState::Uninitialized),
+        }
     }
 
     fn init(&self) {
@@ -87,19 +93,35 @@ impl Lock {
             cell
         };
 
-        let mutref = unsafe { self.0.get().as_mut() }.expect("Should never fail");
+        let mutref = unsafe { self.state.get().as_mut() }.expect("Should never fail");
         *mutref = LockState::Initialized(data.into_inner());
     }
 
-    pub fn lock(&self) {
-        let mutref = unsafe { self.0.get().as_mut() }.expect("Should never fail");
-        let lock = match mutref {
-            LockState::Uninitialized => {
+    /// Build the OS primitive on first call, exactly once across however
+    /// many threads race here. Callers still go through `self.state.get()`
+    /// themselves afterward -- this only guarantees it's safe to dereference
+    /// as `LockState::Initialized` by the time it returns.
+    fn ensure_init(&self) {
+        if self.init_state.load(Ordering::Acquire) != INITIALIZED {
+            if self
+                .init_state
+                .compare_exchange(UNINIT, INITIALIZING, Ordering::Acquire, Ordering::Acquire)
+                .is_ok()
+            {
                 self.init();
-                mutref.unwrap_initialized()
+                self.init_state.store(INITIALIZED, Ordering::Release);
+            } else {
+                while self.init_state.load(Ordering::Acquire) != INITIALIZED {
+                    core::hint::spin_loop();
+                }
             }
-            LockState::Initialized(lock) => lock,
-        };
+        }
+    }
+
+    pub fn lock(&self) {
+        self.ensure_init();
+        let mutref = unsafe { self.state.get().as_mut() }.expect("Should never fail");
+        let lock = mutref.unwrap_initialized();
 
         #[cfg(not(target_os = "windows"))]
         unsafe {
@@ -111,15 +133,27 @@ impl Lock {
         }
     }
 
+    /// Try to acquire the lock without blocking. Returns `false` if it's
+    /// already held.
+    pub fn try_lock(&self) -> bool {
+        self.ensure_init();
+        let mutref = unsafe { self.state.get().as_mut() }.expect("Should never fail");
+        let lock = mutref.unwrap_initialized();
+
+        #[cfg(not(target_os = "windows"))]
+        unsafe {
+            pthread_mutex_trylock(lock as *mut pthread_mutex_t) == 0
+        }
+        #[cfg(target_os = "windows")]
+        unsafe {
+            TryAcquireSRWLockExclusive(lock as *mut SRWLOCK) != 0
+        }
+    }
+
     pub fn unlock(&self) {
-        let mutref = unsafe { self.0.get().as_mut() }.expect("Should never fail");
-        let lock = match mutref {
-            LockState::Uninitialized => {
-                self.init();
-                mutref.unwrap_initialized()
-            }
-            LockState::Initialized(lock) => lock,
-        };
+        self.ensure_init();
+        let mutref = unsafe { self.state.get().as_mut() }.expect("Should never fail");
+        let lock = mutref.unwrap_initialized();
 
         #[cfg(not(target_os = "windows"))]
         unsafe {
@@ -130,26 +164,12 @@ impl Lock {
             ReleaseSRWLockExclusive(lock as *mut SRWLOCK)
         }
     }
-
-    pub fn reset(&self) {
-        let mutptr = unsafe { self.0.get().as_mut().expect("Should never fail") };
-        match mutptr {
-            LockState::Uninitialized => (),
-            #[cfg(not(target_os = "windows"))]
-            LockState::Initialized(lock) => unsafe {
-                core::ptr::drop_in_place(lock as *mut pthread_mutex_t);
-                *mutptr = LockState::Uninitialized;
-            },
-            #[cfg(target_os = "windows")]
-            _ => (),
-        }
-    }
 }
 
 #[cfg(not(target_os = "windows"))]
 impl Drop for Lock {
     fn drop(&mut self) {
-        let mutref = unsafe { self.0.get().as_mut() }.expect("Should never fail");
+        let mutref = unsafe { self.state.get().as_mut() }.expect("Should never fail");
         match mutref {
             LockState::Initialized(lock) => unsafe {
                 pthread_mutex_destroy(lock as *mut pthread_mutex_t);
@@ -0,0 +1,114 @@
+#![allow(non_camel_case_types)]
+
+use core::{
+    ptr,
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
+
+/// A waiter's slot in the MCS queue. Its address must stay stable for as
+/// long as it's queued, so callers keep it behind a `Box`.
+pub struct Node {
+    next: AtomicPtr<Node>,
+    locked: AtomicBool,
+}
+
+impl Node {
+    pub const fn new() -> Self {
+        Self {
+            next: AtomicPtr::new(ptr::null_mut()),
+            locked: AtomicBool::new(true),
+        }
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A fair, FIFO queue lock (Mellor-Crummey & Scott). Unlike the `use-locks`
+/// or `spin-locks` backends, acquiring and releasing needs the caller's own
+/// [`Node`], since the queue is threaded through the waiters themselves
+/// rather than a single shared word, which is what gives it cache-friendly
+/// local spinning and strict ordering.
+pub struct Lock {
+    tail: AtomicPtr<Node>,
+}
+
+impl Lock {
+    pub const fn new() -> Self {
+        Self {
+            tail: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Join the queue behind `node`, spinning on `node.locked` until it's
+    /// this node's turn.
+    pub fn lock(&self, node: &Node) {
+        node.next.store(ptr::null_mut(), Ordering::Relaxed);
+        node.locked.store(true, Ordering::Relaxed);
+
+        let node_ptr = node as *const Node as *mut Node;
+        let pred = self.tail.swap(node_ptr, Ordering::AcqRel);
+        if pred.is_null() {
+            // The queue was empty: we own the lock immediately.
+            return;
+        }
+
+        // SAFETY: `pred` was swapped out of `tail`, so it's the address of a
+        // node that is still queued (it only leaves the queue, and stops
+        // being a valid target, after it runs `unlock`, which happens-after
+        // this swap by virtue of the `tail` CAS in `unlock` failing first).
+        let pred = unsafe { &*pred };
+        pred.next.store(node_ptr, Ordering::Release);
+        while node.locked.load(Ordering::Acquire) {
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Try to acquire the lock without blocking. Succeeds only if the queue
+    /// is currently empty; on failure `node` is left unqueued.
+    pub fn try_lock(&self, node: &Node) -> bool {
+        node.next.store(ptr::null_mut(), Ordering::Relaxed);
+        node.locked.store(true, Ordering::Relaxed);
+
+        let node_ptr = node as *const Node as *mut Node;
+        self.tail
+            .compare_exchange(ptr::null_mut(), node_ptr, Ordering::AcqRel, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    /// Release the lock held via `node`, waking the next waiter if one has
+    /// already enqueued behind us.
+    pub fn unlock(&self, node: &Node) {
+        let node_ptr = node as *const Node as *mut Node;
+        if node.next.load(Ordering::Acquire).is_null() {
+            if self
+                .tail
+                .compare_exchange(node_ptr, ptr::null_mut(), Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                // No one was behind us: the queue is now empty.
+                return;
+            }
+
+            // A successor is mid-enqueue; wait for it to publish itself.
+            while node.next.load(Ordering::Acquire).is_null() {
+                core::hint::spin_loop();
+            }
+        }
+
+        // SAFETY: the successor stored its address into `node.next` before
+        // spinning on its own `locked` flag, so the pointer stays live until
+        // we wake it here.
+        let next = unsafe { &*node.next.load(Ordering::Acquire) };
+        next.locked.store(false, Ordering::Release);
+    }
+}
+
+impl Default for Lock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,78 @@
+#![allow(non_camel_case_types)]
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A strategy for waiting between failed attempts to acquire a [`Lock`].
+pub trait Relax {
+    fn relax();
+}
+
+/// Busy-spin using [`core::hint::spin_loop`]. Never yields to the OS
+/// scheduler, so this is the only option available without `std`.
+pub struct Spin;
+
+impl Relax for Spin {
+    fn relax() {
+        core::hint::spin_loop();
+    }
+}
+
+/// Yield the current OS thread between attempts, via [`std::thread::yield_now`].
+#[cfg(feature = "std")]
+pub struct Yield;
+
+#[cfg(feature = "std")]
+impl Relax for Yield {
+    fn relax() {
+        extern crate std;
+        std::thread::yield_now();
+    }
+}
+
+/// A pure-userspace spinlock with no OS dependency, so it works on `no_std`
+/// targets where there's no pthread/SRWLOCK to back the `use-locks` backend.
+pub struct Lock {
+    locked: AtomicBool,
+}
+
+impl Lock {
+    pub const fn new() -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+        }
+    }
+
+    /// Spin until the lock is acquired, relaxing with [`Spin`] between attempts.
+    pub fn lock(&self) {
+        self.lock_with::<Spin>();
+    }
+
+    /// Spin until the lock is acquired, relaxing with `R` between attempts.
+    pub fn lock_with<R: Relax>(&self) {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            R::relax();
+        }
+    }
+
+    /// Try to acquire the lock without blocking. Returns `false` if it's
+    /// already held.
+    pub fn try_lock(&self) -> bool {
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    pub fn unlock(&self) {
+        self.locked.store(false, Ordering::Release);
+    }
+}
+
+impl Default for Lock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
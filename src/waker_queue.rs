@@ -0,0 +1,145 @@
+use core::{
+    cell::UnsafeCell,
+    ptr,
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+    task::Waker,
+};
+
+/// A node in the intrusive list of wakers queued on a [`WakerQueue`]. Lives
+/// inside the waiting future, which must stay pinned for as long as it's
+/// queued — guaranteed by `Future::poll` taking `Pin<&mut Self>`, as long as
+/// the future removes itself from the queue before it drops or moves.
+pub struct WakerNode {
+    waker: UnsafeCell<Option<Waker>>,
+    next: AtomicPtr<WakerNode>,
+    /// Whether the node is currently linked into some `WakerQueue`. Flipped
+    /// by `push`/`pop`/`remove` under the queue's external lock, and read by
+    /// the owning future to tell "still linked, just update the waker" from
+    /// "already popped, must re-push" — `pop` can race a re-poll that loses
+    /// the subsequent `acquire_write`, so the future can't assume it's still
+    /// queued just because it was before.
+    linked: AtomicBool,
+}
+
+impl WakerNode {
+    pub const fn new() -> Self {
+        Self {
+            waker: UnsafeCell::new(None),
+            next: AtomicPtr::new(ptr::null_mut()),
+            linked: AtomicBool::new(false),
+        }
+    }
+
+    /// Overwrite the stored waker, e.g. to keep it up to date across polls
+    /// while still queued.
+    ///
+    /// # Safety
+    /// The caller must hold the owning [`WakerQueue`]'s external lock.
+    pub unsafe fn set_waker(&self, waker: Waker) {
+        unsafe {
+            *self.waker.get() = Some(waker);
+        }
+    }
+
+    /// Whether the node is currently linked into its queue.
+    ///
+    /// # Safety
+    /// The caller must hold the owning [`WakerQueue`]'s external lock.
+    pub unsafe fn is_linked(&self) -> bool {
+        self.linked.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for WakerNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The head of an intrusive, singly-linked list of waiting [`Waker`]s.
+/// Every method here requires the caller to already hold some external
+/// mutual-exclusion (a spinlock bit on `StateCell`, in this crate), since
+/// the list itself has no internal synchronization.
+pub struct WakerQueue {
+    head: AtomicPtr<WakerNode>,
+}
+
+impl WakerQueue {
+    pub const fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Store `waker` into `node` and push it to the front of the queue.
+    ///
+    /// # Safety
+    /// The caller must hold the queue's external lock, and `node` must stay
+    /// at a stable address and outlive its time in the queue (removed via
+    /// [`remove`] before it moves or is dropped).
+    ///
+    /// [`remove`]: WakerQueue::remove
+    pub unsafe fn push(&self, node: *const WakerNode, waker: Waker) {
+        unsafe {
+            *(*node).waker.get() = Some(waker);
+            let head = self.head.load(Ordering::Relaxed);
+            (*node).next.store(head, Ordering::Relaxed);
+            (*node).linked.store(true, Ordering::Relaxed);
+        }
+        self.head.store(node as *mut WakerNode, Ordering::Relaxed);
+    }
+
+    /// Unlink `node` from the queue if it's still in it. A no-op if it
+    /// isn't (e.g. it was already woken and popped).
+    ///
+    /// # Safety
+    /// The caller must hold the queue's external lock.
+    pub unsafe fn remove(&self, node: *const WakerNode) {
+        let target = node as *mut WakerNode;
+        let mut current = self.head.load(Ordering::Relaxed);
+        if current == target {
+            let next = unsafe { (*target).next.load(Ordering::Relaxed) };
+            self.head.store(next, Ordering::Relaxed);
+            unsafe { (*target).linked.store(false, Ordering::Relaxed) };
+            return;
+        }
+
+        while !current.is_null() {
+            // SAFETY: every pointer reachable from `head` is a live, queued
+            // node (see the safety requirements on `push` and `remove`).
+            let next = unsafe { (*current).next.load(Ordering::Relaxed) };
+            if next == target {
+                let target_next = unsafe { (*target).next.load(Ordering::Relaxed) };
+                unsafe { (*current).next.store(target_next, Ordering::Relaxed) };
+                unsafe { (*target).linked.store(false, Ordering::Relaxed) };
+                return;
+            }
+            current = next;
+        }
+    }
+
+    /// Pop the front of the queue and take its waker, if any waiter is
+    /// queued. The caller wakes it after releasing the queue's lock, so the
+    /// `Waker`'s own (potentially slow) wake-up logic doesn't run while
+    /// other enqueue/dequeue calls are blocked on it.
+    ///
+    /// # Safety
+    /// The caller must hold the queue's external lock.
+    pub unsafe fn pop(&self) -> Option<Waker> {
+        let head = self.head.load(Ordering::Relaxed);
+        if head.is_null() {
+            return None;
+        }
+        // SAFETY: see `remove`.
+        let node = unsafe { &*head };
+        self.head.store(node.next.load(Ordering::Relaxed), Ordering::Relaxed);
+        node.linked.store(false, Ordering::Relaxed);
+        unsafe { (*node.waker.get()).take() }
+    }
+}
+
+impl Default for WakerQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}